@@ -471,6 +471,10 @@ pub mod spiral_token {
             global_bridge_out_limit: mint_data.global_bridge_out_limit,
             trusted_remote_count: mint_data.trusted_remote_count,
             event_seq: mint_data.event_seq,
+            rounded_down: mint_data.rounded_down,
+            rounded_up: mint_data.rounded_up,
+            freeze_authority_recorded: mint_data.freeze_authority_recorded,
+            freeze_authority: mint_data.freeze_authority,
         })
     }
 
@@ -558,6 +562,33 @@ pub mod spiral_token {
         Ok(())
     }
 
+    /// Ends the migration off the legacy registry: from `dual_read_until` on, replay checks
+    /// only consult ProcessedNonce PDAs. Pick a time after which no message consumed in
+    /// the legacy stores can still be delivered. 0 keeps reading both stores for good.
+    pub fn set_dual_read_until(ctx: Context<UpdateConfig>, dual_read_until: i64) -> Result<()> {
+        let mint_data = &mut ctx.accounts.mint_data;
+        require!(
+            ctx.accounts.authority.key() == mint_data.authority,
+            ErrorCode::InvalidAuthority
+        );
+        let now = Clock::get()?.unix_timestamp;
+        // A deadline already past would drop the legacy checks without notice
+        require!(
+            dual_read_until == 0 || dual_read_until > now,
+            ErrorCode::InvalidDualReadUntil
+        );
+        
+        mint_data.record_config_change(now)?;
+        mint_data.dual_read_until = dual_read_until;
+        
+        emit!(DualReadUntilSet {
+            dual_read_until,
+            event_seq: mint_data.next_event_seq()?,
+        });
+        
+        Ok(())
+    }
+
     /// Moves the legacy registry's nonces into a new NonceArchive under a commitment and
     /// empties the registry. The bloom filter still covers them, so receives keep
     /// rejecting them through the archive.
@@ -610,6 +641,7 @@ pub mod spiral_token {
         let (processed, archive_infos) = ctx.remaining_accounts.split_at(nonces.len());
         
         let mint_data = &ctx.accounts.mint_data;
+        let reads_legacy = mint_data.reads_legacy_nonces(Clock::get()?.unix_timestamp);
         let mut archives: Option<Vec<NonceArchive>> = None;
         let mut bitmap = vec![0u8; nonces.len().div_ceil(8)];
         for (i, nonce) in nonces.iter().enumerate() {
@@ -621,7 +653,7 @@ pub mod spiral_token {
             );
             // Same lookup order as receive: a bloom miss proves the nonce is not legacy
             let used = (*info.owner == crate::ID && !info.data_is_empty())
-                || (mint_data.nonce_registry != Pubkey::default()
+                || (reads_legacy
                     && mint_data.nonce_bloom_may_contain(nonce)
                     && (ctx.accounts.nonce_registry.as_ref()
                         .ok_or(ErrorCode::InvalidNonceRegistry)?
@@ -880,6 +912,14 @@ pub mod spiral_token {
             ctx.accounts.pending_outbound.is_some(),
         )?;
         
+        // Only a held transfer can be cancelled, so only it can name a refund recipient.
+        // A delegate may spend the owner's tokens but not redirect a refund of them.
+        if let Some(refund_recipient) = ctx.accounts.refund_recipient.as_ref() {
+            require!(ctx.accounts.pending_outbound.is_some(), ErrorCode::PendingOutboundNotHeld);
+            require_keys_eq!(ctx.accounts.sender_authority.key(), ctx.accounts.sender.owner, ErrorCode::Unauthorized);
+            require_keys_eq!(refund_recipient.mint, ctx.accounts.mint.key(), ErrorCode::WrongMint);
+        }
+        
         let (amount_sd, amounts) = OutboundBurn {
             mint: &ctx.accounts.mint,
             mint_data: &mut ctx.accounts.mint_data,
@@ -918,9 +958,12 @@ pub mod spiral_token {
             // Hold the transfer back so a mistake can still be cancelled; relayers only
             // act on the Initiated event, which settle_outbound publishes later
             pending.sender = ctx.accounts.sender.key();
+            pending.refund_recipient = ctx.accounts.refund_recipient.as_ref()
+                .map(|refund_recipient| refund_recipient.key());
             pending.settle_after = now.checked_add(mint_data.settlement_delay)
                 .ok_or(ErrorCode::CounterOverflow)?;
             pending.amounts = amounts;
+            pending.rounded_up = amounts.rounded_up;
             pending.transfer_info = transfer_info;
             // No event sequence is assigned until the transfer is published
             return Ok(0);
@@ -960,8 +1003,8 @@ pub mod spiral_token {
             SourceChain::Legacy(destination_chain),
         )?;
         
-        let (amount_sd, dust, remote_amount) = mint_data.split_outbound(chain_config.as_ref(), amount)?;
-        let amount_sent = amount - dust;
+        let (amount_sd, dust, rounded_up, remote_amount) = mint_data.split_outbound(chain_config.as_ref(), amount)?;
+        let amount_sent = amount - dust + rounded_up;
         
         // The fee depends on the message size, not on who receives it
         let mut message = vec![0u8; 32];
@@ -983,6 +1026,7 @@ pub mod spiral_token {
             tier_fee: mint_data.tier_fee(amount_sent)?,
             amount_sent,
             dust_removed: dust,
+            rounded_up,
             remote_amount,
         })
     }
@@ -1005,7 +1049,7 @@ pub mod spiral_token {
             ErrorCode::SettlementDelayActive
         );
         
-        let event_seq = mint_data.publish_outbound(pending.transfer_info.clone(), pending.amounts)?;
+        let event_seq = mint_data.publish_outbound(pending.transfer_info.clone(), pending.amounts())?;
         OutboundDelivery {
             mint_data,
             trusted_remote: &ctx.accounts.trusted_remote,
@@ -1022,8 +1066,8 @@ pub mod spiral_token {
     }
 
     /// Undoes a held outbound transfer within its settlement delay by re-minting the
-    /// burned amount to the original sender account and releasing what it counted
-    /// toward the outbound caps and limits
+    /// burned amount to its refund account (the sender account unless the transfer named
+    /// a refund recipient) and releasing what it counted toward the outbound caps and limits
     pub fn cancel_outbound(
         ctx: Context<CancelOutbound>,
         _nonce: [u8; 32],
@@ -1040,13 +1084,18 @@ pub mod spiral_token {
             ErrorCode::SettlementWindowClosed
         );
         require_keys_eq!(ctx.accounts.sender.key(), pending.sender, ErrorCode::InvalidRecipient);
+        let refund_to = match ctx.accounts.refund_recipient.as_ref() {
+            Some(refund_recipient) => refund_recipient.to_account_info(),
+            None => ctx.accounts.sender.to_account_info(),
+        };
+        require_keys_eq!(refund_to.key(), pending.refund_account(), ErrorCode::InvalidRecipient);
         
         // The protocol fee is not refunded
         let amount = pending.amounts.amount_burned;
         mint_as_program(
             &ctx.accounts.token_program,
             &ctx.accounts.mint,
-            ctx.accounts.sender.to_account_info(),
+            refund_to.clone(),
             &ctx.accounts.mint_authority,
             mint_data.key(),
             ctx.bumps.mint_authority,
@@ -1056,6 +1105,8 @@ pub mod spiral_token {
         // Restores exactly what the burn removed, so max supply cannot be exceeded
         mint_data.current_supply = mint_data.current_supply.checked_add(amount)
             .ok_or(ErrorCode::SupplyOverflow)?;
+        mint_data.rounded_down = mint_data.rounded_down.saturating_sub(pending.amounts.dust_removed);
+        mint_data.rounded_up = mint_data.rounded_up.saturating_sub(pending.rounded_up);
         
         // Give the transfer's share of the outbound limits back. Only cross_chain_transfer
         // holds transfers back, so the destination is a legacy chain id
@@ -1079,6 +1130,7 @@ pub mod spiral_token {
         
         emit!(OutboundCancelled {
             sender: pending.sender,
+            refunded_to: refund_to.key(),
             amount,
             nonce: pending.transfer_info.nonce,
             event_seq: mint_data.next_event_seq()?,
@@ -2603,44 +2655,110 @@ pub mod spiral_token {
         Ok(())
     }
 
+    /// Caps how many quarantined transfers may wait at once; further transfers that
+    /// would be quarantined are refused until one is claimed or vetoed. 0 = unlimited.
+    pub fn set_max_pending_inbound(ctx: Context<UpdateConfig>, max_pending_inbound: u32) -> Result<()> {
+        let mint_data = &mut ctx.accounts.mint_data;
+        require!(
+            ctx.accounts.authority.key() == mint_data.authority,
+            ErrorCode::InvalidAuthority
+        );
+        
+        mint_data.record_config_change(Clock::get()?.unix_timestamp)?;
+        mint_data.max_pending_inbound = max_pending_inbound;
+        
+        emit!(MaxPendingInboundSet {
+            max_pending_inbound,
+            event_seq: mint_data.next_event_seq()?,
+        });
+        
+        Ok(())
+    }
+
     /// Permissionless: mints a quarantined transfer once its delay has passed and
     /// refunds the PendingInbound rent to whoever paid it
     pub fn claim_pending_inbound(ctx: Context<ClaimPendingInbound>) -> Result<()> {
         let mint_data = &mut ctx.accounts.mint_data;
         mint_data.require_op_active(MintData::OP_BRIDGE_IN)?;
         
-        let pending = &ctx.accounts.pending_inbound;
+        ctx.accounts.pending_inbound.claim(
+            mint_data,
+            ProgramMint {
+                token_program: &ctx.accounts.token_program,
+                mint: &ctx.accounts.mint,
+                mint_authority: &ctx.accounts.mint_authority,
+                mint_authority_bump: ctx.bumps.mint_authority,
+            },
+            ctx.accounts.recipient.to_account_info(),
+            Clock::get()?.unix_timestamp,
+        )
+    }
+
+    /// Permissionless: `claim_pending_inbound` for up to `MAX_BATCH` quarantined transfers
+    /// at once, named by the seeds of their PendingInbound. Transfers still in quarantine
+    /// are skipped and stay open, as are ones already claimed or vetoed; returns how many
+    /// were claimed.
+    pub fn claim_pending_inbound_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimPendingInboundBatch<'info>>,
+        entries: Vec<PendingInboundId>,
+    ) -> Result<u32> {
+        let accounts = ctx.remaining_accounts.chunks_exact(3);
         require!(
-            Clock::get()?.unix_timestamp >= pending.unlock_ts,
-            ErrorCode::QuarantineNotElapsed
+            accounts.remainder().is_empty()
+                && accounts.len() == entries.len()
+                && (1..=ClaimPendingInboundBatch::MAX_BATCH).contains(&entries.len()),
+            ErrorCode::InvalidBatchSize
         );
         
-        // Reserved against max supply when the transfer was quarantined
-        mint_as_program(
-            &ctx.accounts.token_program,
-            &ctx.accounts.mint,
-            ctx.accounts.recipient.to_account_info(),
-            &ctx.accounts.mint_authority,
-            mint_data.key(),
-            ctx.bumps.mint_authority,
-            pending.amount,
-        )?;
+        let mint_data = &mut ctx.accounts.mint_data;
+        mint_data.require_op_active(MintData::OP_BRIDGE_IN)?;
+        let mint_data_key = mint_data.key();
+        let now = Clock::get()?.unix_timestamp;
+        let (mut claimed, mut skipped) = (0u32, 0u32);
         
-        mint_data.ledger_outstanding = mint_data.ledger_outstanding.checked_sub(pending.amount)
-            .ok_or(ErrorCode::SupplyUnderflow)?;
-        mint_data.current_supply = mint_data.current_supply.checked_add(pending.amount)
-            .ok_or(ErrorCode::SupplyOverflow)?;
+        for (entry, accounts) in entries.iter().zip(accounts) {
+            let (pending_info, recipient, payer) = (&accounts[0], &accounts[1], &accounts[2]);
+            require_keys_eq!(
+                pending_info.key(),
+                PendingInbound::address(mint_data_key, entry.source, &entry.nonce),
+                ErrorCode::InvalidPendingInbound
+            );
+            // Claimed or vetoed since the batch was built, or listed twice
+            if pending_info.data_is_empty() {
+                skipped += 1;
+                continue;
+            }
+            let pending = Account::<PendingInbound>::try_from(pending_info)?;
+            require_keys_eq!(recipient.key(), pending.recipient, ErrorCode::InvalidRecipient);
+            require_keys_eq!(payer.key(), pending.payer, ErrorCode::InvalidRecipient);
+            if now < pending.unlock_ts {
+                skipped += 1;
+                continue;
+            }
+            
+            pending.claim(
+                mint_data,
+                ProgramMint {
+                    token_program: &ctx.accounts.token_program,
+                    mint: &ctx.accounts.mint,
+                    mint_authority: &ctx.accounts.mint_authority,
+                    mint_authority_bump: ctx.bumps.mint_authority,
+                },
+                recipient.clone(),
+                now,
+            )?;
+            pending.close(payer.clone())?;
+            claimed += 1;
+        }
         
-        emit!(PendingInboundClaimed {
-            source_chain: pending.source.id(),
-            nonce: pending.nonce,
-            recipient: pending.recipient,
-            amount: pending.amount,
+        emit!(PendingInboundBatchClaimed {
+            claimed,
+            skipped,
             new_supply: mint_data.current_supply,
             event_seq: mint_data.next_event_seq()?,
         });
         
-        Ok(())
+        Ok(claimed)
     }
 
     /// Cancels a quarantined transfer before it is claimed; nothing is minted for it.
@@ -2656,6 +2774,7 @@ pub mod spiral_token {
         let pending = &ctx.accounts.pending_inbound;
         mint_data.ledger_outstanding = mint_data.ledger_outstanding.checked_sub(pending.amount)
             .ok_or(ErrorCode::SupplyUnderflow)?;
+        mint_data.close_pending_inbound();
         
         emit!(PendingInboundVetoed {
             source_chain: pending.source.id(),
@@ -2817,6 +2936,28 @@ pub mod spiral_token {
         Ok(())
     }
 
+    /// How outbound transfers treat an amount that isn't a whole number of shared units
+    pub fn set_rounding_mode(
+        ctx: Context<UpdateConfig>,
+        rounding_mode: RoundingMode,
+    ) -> Result<()> {
+        let mint_data = &mut ctx.accounts.mint_data;
+        require!(
+            ctx.accounts.authority.key() == mint_data.authority,
+            ErrorCode::InvalidAuthority
+        );
+        
+        mint_data.record_config_change(Clock::get()?.unix_timestamp)?;
+        mint_data.rounding_mode = rounding_mode;
+        
+        emit!(RoundingModeSet {
+            rounding_mode,
+            event_seq: mint_data.next_event_seq()?,
+        });
+        
+        Ok(())
+    }
+
     /// Records the token's decimals on `chain`, used to report what a destination credits
     pub fn set_chain_decimals(
         ctx: Context<UpdateChainConfig>,
@@ -3070,12 +3211,14 @@ pub mod spiral_token {
     }
 
     /// Migration for mints created before the program held the mint authority:
-    /// hands the SPL mint authority from the authority key to the program PDA
+    /// hands the SPL mint authority from the authority key to the program PDA and
+    /// records the mint's freeze authority, which the program never holds
     pub fn adopt_program_mint_authority(ctx: Context<AdoptProgramMintAuthority>) -> Result<()> {
         require!(
             ctx.accounts.authority.key() == ctx.accounts.mint_data.authority,
             ErrorCode::InvalidAuthority
         );
+        ctx.accounts.mint_data.record_freeze_authority(&ctx.accounts.mint);
         
         let cpi_accounts = token_interface::SetAuthority {
             current_authority: ctx.accounts.authority.to_account_info(),
@@ -3092,6 +3235,13 @@ pub mod spiral_token {
         Ok(())
     }
 
+    /// Permissionless: re-reads the mint's freeze authority into MintData, for mints adopted
+    /// before it was recorded and for changes made to it on the mint since
+    pub fn refresh_freeze_authority(ctx: Context<RefreshFreezeAuthority>) -> Result<()> {
+        ctx.accounts.mint_data.record_freeze_authority(&ctx.accounts.mint);
+        Ok(())
+    }
+
     /// One-off upgrade for a MintData created before it was a PDA of its mint: copies its
     /// state into the [b"mint_data", mint] PDA, hands the SPL mint authority to the new
     /// account's mint authority PDA and closes the legacy account. `remaining_accounts`
@@ -3158,6 +3308,7 @@ pub mod spiral_token {
         )?;
         
         state.mint = ctx.accounts.mint.key();
+        state.record_freeze_authority(&ctx.accounts.mint);
         ctx.accounts.mint_data.set_inner(state);
         
         // Close the legacy account; its rent goes to the authority
@@ -3817,10 +3968,11 @@ pub mod spiral_token {
         let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &crate::ID).0;
         let (oapp, _) = LzEndpoint::oapp(&mint_data_key);
         
-        let nonce_registry = if mint_data.nonce_registry == Pubkey::default() {
-            crate::ID
-        } else {
+        // Omitted once the dual-read window has closed, as replay checks no longer read it
+        let nonce_registry = if mint_data.reads_legacy_nonces(Clock::get()?.unix_timestamp) {
             mint_data.nonce_registry
+        } else {
+            crate::ID
         };
         let claimable_balance = if mint_data.ledger_only {
             pda(&[b"claimable", mint_data_key.as_ref(), to.as_ref()])
@@ -4065,6 +4217,7 @@ pub mod spiral_token {
             recipient_length: recipient.length,
             amount_burned: amounts.amount_burned,
             dust_removed: amounts.dust_removed,
+            rounded_up: amounts.rounded_up,
             remote_amount: amounts.remote_amount,
             sequence,
             outbound_commitment: mint_data.outbound_commitment,
@@ -4216,6 +4369,7 @@ pub mod spiral_token {
             recipient_length: recipient.length,
             amount_burned: amounts.amount_burned,
             dust_removed: amounts.dust_removed,
+            rounded_up: amounts.rounded_up,
             remote_amount: amounts.remote_amount,
             message_id,
             outbound_commitment: mint_data.outbound_commitment,
@@ -4427,6 +4581,7 @@ pub mod spiral_token {
             recipient_length: recipient.length,
             amount_burned: amounts.amount_burned,
             dust_removed: amounts.dust_removed,
+            rounded_up: amounts.rounded_up,
             remote_amount: amounts.remote_amount,
             nonce,
             outstanding: ctx.accounts.bridge_adapter.outstanding,
@@ -5104,6 +5259,10 @@ pub struct CrossChainTransfer<'info> {
     )]
     pub pending_outbound: Option<Account<'info, PendingOutbound>>,
     
+    /// Token account of the mint a held transfer is re-minted to if cancelled, for
+    /// custodial setups; defaults to `sender`. Only the sender's owner may name one.
+    pub refund_recipient: Option<InterfaceAccount<'info, TokenAccount>>,
+    
     pub sender_authority: Signer<'info>,
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -5204,6 +5363,10 @@ pub struct CancelOutbound<'info> {
     #[account(mut)]
     pub sender: InterfaceAccount<'info, TokenAccount>,
     
+    /// The held transfer's refund recipient, when it named one instead of `sender`
+    #[account(mut)]
+    pub refund_recipient: Option<InterfaceAccount<'info, TokenAccount>>,
+    
     /// CHECK: BridgeVolume PDA of the mint; verified in `BridgeVolume::release`
     #[account(mut)]
     pub bridge_volume: UncheckedAccount<'info>,
@@ -5454,7 +5617,7 @@ pub struct AdoptProgramMintAuthority<'info> {
     #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
     
-    #[account(has_one = mint @ ErrorCode::MintDataMismatch)]
+    #[account(mut, has_one = mint @ ErrorCode::MintDataMismatch)]
     pub mint_data: Account<'info, MintData>,
     
     /// CHECK: program PDA that will hold the SPL mint authority
@@ -5465,6 +5628,14 @@ pub struct AdoptProgramMintAuthority<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+#[derive(Accounts)]
+pub struct RefreshFreezeAuthority<'info> {
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut, has_one = mint @ ErrorCode::MintDataMismatch)]
+    pub mint_data: Account<'info, MintData>,
+}
+
 #[derive(Accounts)]
 pub struct SetTransferFee<'info> {
     #[account(mut)]
@@ -5928,6 +6099,27 @@ pub struct ClaimPendingInbound<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+#[derive(Accounts)]
+pub struct ClaimPendingInboundBatch<'info> {
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut, has_one = mint @ ErrorCode::MintDataMismatch)]
+    pub mint_data: Account<'info, MintData>,
+    
+    /// CHECK: program PDA that holds the SPL mint authority
+    #[account(seeds = [b"mint_authority", mint_data.key().as_ref()], bump)]
+    pub mint_authority: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    // remaining_accounts: per entry, in order, the writable PendingInbound PDA, its
+    // recipient token account and the account that paid its rent
+}
+
+impl ClaimPendingInboundBatch<'_> {
+    pub const MAX_BATCH: usize = 8; // Each claimed entry is a mint CPI
+}
+
 #[derive(Accounts)]
 pub struct VetoPendingInbound<'info> {
     #[account(mut, seeds = [b"mint_data", mint_data.mint.as_ref()], bump)]
@@ -7390,6 +7582,14 @@ pub struct MintData {
     pub max_minters: u16, // 0 = up to MAX_MINTERS
     pub fee_tiers: Vec<FeeTier>, // Bps fee on outbound amounts by ascending threshold; empty = none
     pub multisig_epoch: u64, // Bumped by set_admin_multisig; proposals of an earlier epoch are void
+    pub pending_inbound_count: u32, // PendingInbound accounts not yet claimed or vetoed
+    pub max_pending_inbound: u32, // Quarantined transfers held at once; 0 = unlimited
+    pub dual_read_until: i64, // Replay checks consult the legacy registry until this time; 0 = for good
+    pub rounding_mode: RoundingMode, // How outbound amounts are brought to whole shared units
+    pub rounded_down: u64, // Local units outbound amounts were rounded down by so far, left with senders
+    pub rounded_up: u64, // Local units senders topped outbound amounts up by so far
+    pub freeze_authority_recorded: bool, // Whether `freeze_authority` reflects the SPL mint
+    pub freeze_authority: Option<Pubkey>, // SPL freeze authority of the mint, once recorded
 }
 
 impl MintData {
@@ -7466,7 +7666,15 @@ impl MintData {
         + 2 // minter_count
         + 2 // max_minters
         + 4 + FeeTier::SPACE * Self::MAX_FEE_TIERS // fee_tiers
-        + 8; // multisig_epoch
+        + 8 // multisig_epoch
+        + 4 // pending_inbound_count
+        + 4 // max_pending_inbound
+        + 8 // dual_read_until
+        + 1 // rounding_mode
+        + 8 // rounded_down
+        + 8 // rounded_up
+        + 1 // freeze_authority_recorded
+        + 1 + 32; // freeze_authority
 
     /// Single source of truth for max_supply bounds; every path that sets it must call this
     pub fn validate_max_supply(value: u64) -> Result<()> {
@@ -7475,6 +7683,23 @@ impl MintData {
         Ok(())
     }
 
+    /// Counts a newly quarantined transfer, failing once `max_pending_inbound` are held
+    pub fn open_pending_inbound(&mut self) -> Result<()> {
+        require!(
+            self.max_pending_inbound == 0 || self.pending_inbound_count < self.max_pending_inbound,
+            ErrorCode::TooManyPending
+        );
+        self.pending_inbound_count = self.pending_inbound_count.checked_add(1)
+            .ok_or(ErrorCode::CounterOverflow)?;
+        Ok(())
+    }
+
+    /// Frees the slot of a claimed or vetoed PendingInbound. Saturates, as transfers
+    /// quarantined before the count was kept were never counted.
+    pub fn close_pending_inbound(&mut self) {
+        self.pending_inbound_count = self.pending_inbound_count.saturating_sub(1);
+    }
+
     // Bits of `paused_ops`
     pub const OP_MINT: u32 = 1 << 0;
     pub const OP_BRIDGE_OUT: u32 = 1 << 1;
@@ -7550,6 +7775,22 @@ impl MintData {
         self.max_minters = 0;
         self.fee_tiers = Vec::new();
         self.multisig_epoch = 0;
+        self.pending_inbound_count = 0;
+        self.max_pending_inbound = 0;
+        self.dual_read_until = 0;
+        self.rounding_mode = RoundingMode::Floor;
+        self.rounded_down = 0;
+        self.rounded_up = 0;
+        // Mints created by the program never get a freeze authority
+        self.freeze_authority_recorded = true;
+        self.freeze_authority = None;
+    }
+
+    /// Records who can freeze accounts of `mint`, which the program adopted rather than
+    /// created. The program never holds that authority.
+    pub fn record_freeze_authority(&mut self, mint: &Mint) {
+        self.freeze_authority_recorded = true;
+        self.freeze_authority = mint.freeze_authority.into();
     }
 
     /// Local units per shared unit: 10^(decimals - shared_decimals), or 1 while scaling is off
@@ -7574,15 +7815,27 @@ impl MintData {
             .ok_or(error!(ErrorCode::SupplyOverflow))
     }
 
-    /// Splits an outbound `amount` into its wire amount, the dust left with the sender
-    /// and what the destination credits, enforcing the destination's minimum transfer
-    pub fn split_outbound(&self, chain_config: Option<&ChainConfig>, amount: u64) -> Result<(u64, u64, u128)> {
-        let (amount_sd, dust) = self.to_shared(amount)?;
+    /// Splits an outbound `amount` into its wire amount, the dust left with the sender,
+    /// what the sender is charged on top and what the destination credits, rounding a
+    /// remainder below one shared unit per `rounding_mode` and enforcing the destination's
+    /// minimum transfer. Inbound amounts scale up exactly, so only this direction rounds.
+    pub fn split_outbound(&self, chain_config: Option<&ChainConfig>, amount: u64) -> Result<(u64, u64, u64, u128)> {
+        let (mut amount_sd, dust) = self.to_shared(amount)?;
+        if dust > 0 {
+            match self.rounding_mode {
+                RoundingMode::Floor => {}
+                RoundingMode::Ceil => amount_sd = amount_sd.checked_add(1).ok_or(ErrorCode::SupplyOverflow)?,
+                RoundingMode::Reject => return err!(ErrorCode::PrecisionLoss),
+            }
+        }
         require!(amount_sd > 0, ErrorCode::AmountBelowSharedUnit);
+        let amount_sent = self.from_shared(amount_sd)?;
         let remote_amount = self.remote_amount(chain_config, amount_sd)?;
         let min_transfer_amount = chain_config.map_or(0, |config| config.min_transfer_amount);
-        require!(amount - dust >= min_transfer_amount, ErrorCode::BelowMinTransferAmount);
-        Ok((amount_sd, dust, remote_amount))
+        require!(amount_sent >= min_transfer_amount, ErrorCode::BelowMinTransferAmount);
+        let dust = amount.saturating_sub(amount_sent);
+        let rounded_up = amount_sent.saturating_sub(amount);
+        Ok((amount_sd, dust, rounded_up, remote_amount))
     }
 
     /// What the destination credits for `amount_sd`, in its local decimals. Without
@@ -7629,6 +7882,13 @@ impl MintData {
         out
     }
 
+    /// Whether replay checks still consult the legacy registry and its archives: while
+    /// one is bound and `dual_read_until` (if set) has not passed
+    pub fn reads_legacy_nonces(&self, now: i64) -> bool {
+        self.nonce_registry != Pubkey::default()
+            && (self.dual_read_until == 0 || now < self.dual_read_until)
+    }
+
     /// False means the nonce was definitely never recorded; true may be a false positive
     pub fn nonce_bloom_may_contain(&self, nonce: &[u8; 32]) -> bool {
         Self::nonce_bloom_bits(nonce)
@@ -7845,6 +8105,7 @@ impl MintData {
             transfer_info,
             amount_burned: amounts.amount_burned,
             dust_removed: amounts.dust_removed,
+            rounded_up: amounts.rounded_up,
             remote_amount: amounts.remote_amount,
             protocol_fee: amounts.protocol_fee,
             fee_token: amounts.fee_token,
@@ -8019,6 +8280,10 @@ pub struct StateView {
     pub global_bridge_out_limit: u64,
     pub trusted_remote_count: u16,
     pub event_seq: u64,
+    pub rounded_down: u64, // Outbound rounding totals, in local units
+    pub rounded_up: u64,
+    pub freeze_authority_recorded: bool, // False for a mint adopted before it was recorded
+    pub freeze_authority: Option<Pubkey>,
 }
 
 /// Canonical PDAs for one chain id, as returned by `derive_addresses`
@@ -8083,15 +8348,17 @@ impl NonceRegistry {
 
     /// Fails if `nonce` was consumed before per-nonce PDAs, i.e. sits in the legacy
     /// registry or its archives. A bloom filter miss proves it is not among them, so
-    /// the registry and archives are only required on a (possibly false) positive
+    /// the registry and archives are only required on a (possibly false) positive.
+    /// After `dual_read_until` only the ProcessedNonce PDAs are consulted.
     pub fn require_unused(
         mint_data_key: Pubkey,
         mint_data: &MintData,
         registry: Option<&NonceRegistry>,
         archives: &[AccountInfo],
         nonce: [u8; 32],
+        now: i64,
     ) -> Result<()> {
-        if mint_data.nonce_registry == Pubkey::default() || !mint_data.nonce_bloom_may_contain(&nonce) {
+        if !mint_data.reads_legacy_nonces(now) || !mint_data.nonce_bloom_may_contain(&nonce) {
             return Ok(());
         }
         let registry = registry.ok_or(ErrorCode::InvalidNonceRegistry)?;
@@ -8191,6 +8458,45 @@ impl PendingInbound {
         ).0
     }
 
+    /// Mints the transfer to `recipient` once its quarantine has passed and frees its
+    /// slot; the caller closes the account
+    pub fn claim<'info>(
+        &self,
+        mint_data: &mut Account<'info, MintData>,
+        accounts: ProgramMint<'_, 'info>,
+        recipient: AccountInfo<'info>,
+        now: i64,
+    ) -> Result<()> {
+        require!(now >= self.unlock_ts, ErrorCode::QuarantineNotElapsed);
+        
+        // Reserved against max supply when the transfer was quarantined
+        mint_as_program(
+            accounts.token_program,
+            accounts.mint,
+            recipient,
+            accounts.mint_authority,
+            mint_data.key(),
+            accounts.mint_authority_bump,
+            self.amount,
+        )?;
+        
+        mint_data.ledger_outstanding = mint_data.ledger_outstanding.checked_sub(self.amount)
+            .ok_or(ErrorCode::SupplyUnderflow)?;
+        mint_data.current_supply = mint_data.current_supply.checked_add(self.amount)
+            .ok_or(ErrorCode::SupplyOverflow)?;
+        mint_data.close_pending_inbound();
+        
+        emit!(PendingInboundClaimed {
+            source_chain: self.source.id(),
+            nonce: self.nonce,
+            recipient: self.recipient,
+            amount: self.amount,
+            new_supply: mint_data.current_supply,
+            event_seq: mint_data.next_event_seq()?,
+        });
+        Ok(())
+    }
+
     /// Creates the PDA of `self` at `info`, paid like `ProcessedNonce::claim`
     pub fn create<'info>(
         &self,
@@ -8219,6 +8525,13 @@ impl PendingInbound {
     }
 }
 
+/// Seeds of a PendingInbound, naming it in `claim_pending_inbound_batch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PendingInboundId {
+    pub source: SourceChain,
+    pub nonce: [u8; 32],
+}
+
 /// LayerZero-style inbound state of one (source chain, remote) path: every nonce up to
 /// `inbound_nonce` is consumed, and `window` tracks the next `WINDOW` nonces out of order
 #[account]
@@ -8324,12 +8637,24 @@ pub struct OutboundAmounts {
     pub fee_token: FeeToken,
     pub fee_waived: bool, // The sender is fee-exempt; `protocol_fee` and `tier_fee` are 0
     pub tier_fee: u64, // SPIRAL charged by `MintData::fee_tiers`
+    // Included in `amount_burned` to reach a whole shared unit. Left out of the stored
+    // layout, which holds were created with; PendingOutbound keeps it after its other fields
+    #[borsh_skip]
+    pub rounded_up: u64,
 }
 
 impl OutboundAmounts {
     pub const SPACE: usize = 8 + 8 + 16 + 8 + 1 + 1 + 8;
 }
 
+/// How an outbound amount with a remainder below one shared unit is sent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    Floor, // The remainder stays with the sender
+    Ceil, // The sender is charged up to the next whole unit
+    Reject, // The transfer fails with PrecisionLoss
+}
+
 /// How the protocol fee on outbound transfers is paid
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum FeeToken {
@@ -8350,18 +8675,36 @@ impl FeeTier {
 }
 
 /// Outbound transfer that has been burned but not yet published to relayers
-#[account]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct PendingOutbound {
     pub sender: Pubkey, // Token account the amount was burned from
     pub settle_after: i64,
     pub amounts: OutboundAmounts,
     pub transfer_info: CrossChainTransferInfo,
+    pub refund_recipient: Option<Pubkey>, // Token account a cancellation re-mints to; None = `sender`
+    pub rounded_up: u64, // `amounts.rounded_up`
 }
 
 impl PendingOutbound {
     // discriminator + sender + settle_after + amounts + transfer_info (payload at its maximum length)
+    // + refund_recipient + rounded_up
     pub const SPACE: usize = 8 + 32 + 8 + OutboundAmounts::SPACE
-        + (2 + 2 + 32 + 1 + 8 + 32 + 8 + 4 + CrossChainTransferInfo::MAX_PAYLOAD_LEN);
+        + (2 + 2 + 32 + 1 + 8 + 32 + 8 + 4 + CrossChainTransferInfo::MAX_PAYLOAD_LEN)
+        + 1 + 32
+        + 8;
+
+    /// Size of holds created before `refund_recipient` and `rounded_up` were appended
+    pub const LEGACY_SPACE: usize = Self::SPACE - (1 + 32) - 8;
+
+    /// What the held transfer burned, charged and delivers
+    pub fn amounts(&self) -> OutboundAmounts {
+        OutboundAmounts { rounded_up: self.rounded_up, ..self.amounts }
+    }
+
+    /// Token account `cancel_outbound` re-mints the burned amount to
+    pub fn refund_account(&self) -> Pubkey {
+        self.refund_recipient.unwrap_or(self.sender)
+    }
 
     /// The hold account is passed exactly when a settlement delay holds transfers back;
     /// without one, its rent would sit in an account nothing ever settles or closes
@@ -8375,6 +8718,46 @@ impl PendingOutbound {
     }
 }
 
+// The account traits are written out instead of derived by #[account] so that holds
+// created before fields were appended still load: a full payload leaves them no room
+// for the new tail, which reads as unset, as legacy MintData does in migrate_mint_data
+impl anchor_lang::Discriminator for PendingOutbound {
+    const DISCRIMINATOR: [u8; 8] = [155, 97, 207, 25, 61, 17, 195, 55]; // sha256("account:PendingOutbound")
+}
+
+impl anchor_lang::Owner for PendingOutbound {
+    fn owner() -> Pubkey {
+        crate::ID
+    }
+}
+
+impl anchor_lang::AccountSerialize for PendingOutbound {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&<Self as anchor_lang::Discriminator>::DISCRIMINATOR)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        AnchorSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+
+impl anchor_lang::AccountDeserialize for PendingOutbound {
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        require!(buf.len() >= 8, anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound);
+        require!(buf[..8] == <Self as anchor_lang::Discriminator>::DISCRIMINATOR, anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch);
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        let mut data = buf.get(8..).unwrap_or_default().to_vec();
+        if data.len() < Self::SPACE - 8 {
+            data.resize(Self::SPACE - 8, 0);
+        }
+        AnchorDeserialize::deserialize(&mut &data[..])
+            .map_err(|_| error!(anchor_lang::error::ErrorCode::AccountDidNotDeserialize))
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum RoleKind {
     Minter,
@@ -9844,7 +10227,7 @@ pub struct ProtocolFee<'a, 'info> {
 }
 
 impl<'a, 'info> OutboundBurn<'a, 'info> {
-    /// Burns `amount`, less the dust below one shared unit, for a transfer to
+    /// Burns `amount`, rounded to whole shared units per `rounding_mode`, for a transfer to
     /// `destination`. Returns the wire amount in shared decimals and what was burned
     /// and charged.
     pub fn burn(self, destination: SourceChain, amount: u64) -> Result<(u64, OutboundAmounts)> {
@@ -9852,10 +10235,15 @@ impl<'a, 'info> OutboundBurn<'a, 'info> {
         mint_data.require_op_active(MintData::OP_BRIDGE_OUT)?;
         let chain_config = ChainConfig::require_active(self.chain_config, mint_data.key(), destination)?;
         
-        // With shared decimals on, the wire amount is in shared units; dust below one
-        // shared unit is never burned and stays with the sender
-        let (amount_sd, dust, remote_amount) = mint_data.split_outbound(chain_config.as_ref(), amount)?;
-        let amount = amount - dust;
+        // With shared decimals on, the wire amount is in shared units. Rounded down, dust
+        // below one shared unit is never burned and stays with the sender; rounded up,
+        // the sender tops the amount up to the next unit
+        let (amount_sd, dust, rounded_up, remote_amount) = mint_data.split_outbound(chain_config.as_ref(), amount)?;
+        mint_data.rounded_down = mint_data.rounded_down.checked_add(dust)
+            .ok_or(ErrorCode::CounterOverflow)?;
+        mint_data.rounded_up = mint_data.rounded_up.checked_add(rounded_up)
+            .ok_or(ErrorCode::CounterOverflow)?;
+        let amount = amount - dust + rounded_up;
         
         let now = Clock::get()?.unix_timestamp;
         mint_data.record_bridge_volume(BridgeDirection::Out, amount, now)?;
@@ -9942,6 +10330,7 @@ impl<'a, 'info> OutboundBurn<'a, 'info> {
             fee_token,
             fee_waived,
             tier_fee,
+            rounded_up,
         }))
     }
 }
@@ -10071,6 +10460,7 @@ impl<'a, 'info> InboundTransfer<'a, 'info> {
                 self.nonce_registry.map(|registry| &**registry),
                 self.nonce_archives,
                 nonce,
+                Clock::get()?.unix_timestamp,
             )?;
 
             // Mark nonce as used; fails if its PDA already exists
@@ -10117,6 +10507,7 @@ impl<'a, 'info> InboundTransfer<'a, 'info> {
         let minted = if quarantined {
             let unlock_ts = now.checked_add(mint_data.quarantine_delay)
                 .ok_or(ErrorCode::SupplyOverflow)?;
+            mint_data.open_pending_inbound()?;
             PendingInbound {
                 source,
                 nonce,
@@ -10412,6 +10803,12 @@ pub struct InboundQuarantined {
     pub event_seq: u64,
 }
 
+#[event]
+pub struct MaxPendingInboundSet {
+    pub max_pending_inbound: u32,
+    pub event_seq: u64,
+}
+
 #[event]
 pub struct PendingInboundClaimed {
     pub source_chain: u32,
@@ -10422,6 +10819,15 @@ pub struct PendingInboundClaimed {
     pub event_seq: u64,
 }
 
+/// Summary of a `claim_pending_inbound_batch`, after the claim of each transfer
+#[event]
+pub struct PendingInboundBatchClaimed {
+    pub claimed: u32,
+    pub skipped: u32, // Still in quarantine, or already claimed or vetoed
+    pub new_supply: u64,
+    pub event_seq: u64,
+}
+
 #[event]
 pub struct PendingInboundVetoed {
     pub source_chain: u32,
@@ -10507,6 +10913,7 @@ impl HealthReport {
 #[event]
 pub struct OutboundCancelled {
     pub sender: Pubkey,
+    pub refunded_to: Pubkey, // Token account the amount was re-minted to
     pub amount: u64,
     pub nonce: [u8; 32],
     pub event_seq: u64,
@@ -10576,6 +10983,7 @@ pub struct BridgeAdapterSent {
     pub recipient_length: u8,
     pub amount_burned: u64,
     pub dust_removed: u64,
+    pub rounded_up: u64,
     pub remote_amount: u128,
     pub nonce: [u8; 32],
     pub outstanding: u64, // Adapter's outstanding amount after this burn
@@ -10605,6 +11013,7 @@ pub struct HyperlaneTransferDispatched {
     pub recipient_length: u8,
     pub amount_burned: u64,
     pub dust_removed: u64,
+    pub rounded_up: u64,
     pub remote_amount: u128,
     pub message_id: [u8; 32],
     pub outbound_commitment: [u8; 32],
@@ -10618,6 +11027,7 @@ pub struct WormholeTransferPosted {
     pub recipient_length: u8,
     pub amount_burned: u64,
     pub dust_removed: u64,
+    pub rounded_up: u64,
     pub remote_amount: u128,
     pub sequence: u64, // Wormhole sequence of our emitter; identifies the VAA
     pub outbound_commitment: [u8; 32],
@@ -10636,6 +11046,12 @@ pub struct SharedDecimalsUpdated {
     pub event_seq: u64,
 }
 
+#[event]
+pub struct RoundingModeSet {
+    pub rounding_mode: RoundingMode,
+    pub event_seq: u64,
+}
+
 #[event]
 pub struct ChainDecimalsSet {
     pub chain: SourceChain,
@@ -10898,6 +11314,12 @@ pub struct NonceRegistryInitialized {
     pub event_seq: u64,
 }
 
+#[event]
+pub struct DualReadUntilSet {
+    pub dual_read_until: i64,
+    pub event_seq: u64,
+}
+
 #[event]
 pub struct NonceRegistryRotated {
     pub archive: Pubkey,
@@ -10951,6 +11373,7 @@ pub struct CrossChainTransferInitiated {
     pub transfer_info: CrossChainTransferInfo, // `amount` is in shared decimals when scaling is on
     pub amount_burned: u64, // Local amount burned on Solana
     pub dust_removed: u64, // Requested amount below one shared unit, left with the sender
+    pub rounded_up: u64, // Burned beyond the requested amount to reach a whole shared unit
     pub remote_amount: u128, // Amount credited on the destination, in its local decimals
     pub protocol_fee: u64, // In lamports or SPIRAL base units, per `fee_token`
     pub fee_token: FeeToken,
@@ -11488,6 +11911,7 @@ pub struct SendQuote {
     pub tier_fee: u64, // SPIRAL taken from the sender on top of `amount_sent`
     pub amount_sent: u64, // Local amount that would be burned
     pub dust_removed: u64,
+    pub rounded_up: u64, // Charged beyond `amount` to reach a whole shared unit
     pub remote_amount: u128, // Amount credited on the destination, in its local decimals
 }
 
@@ -12088,6 +12512,12 @@ pub enum ErrorCode {
     StaleProposal,
     #[msg("Pending outbound account must be omitted while no settlement delay is set")]
     PendingOutboundNotHeld,
+    #[msg("Too many quarantined transfers are waiting to be claimed")]
+    TooManyPending,
+    #[msg("Dual-read deadline must be 0 or in the future")]
+    InvalidDualReadUntil,
+    #[msg("Amount is not a whole number of shared-decimals units")]
+    PrecisionLoss,
 }
 
 #[cfg(test)]
@@ -12665,16 +13095,200 @@ mod tests {
     }
 
     #[test]
-    fn roles_are_bound_to_their_kind_and_member() {
-        let (address, authority, minter, other) = (key(1), key(2), key(3), key(4));
+    fn outbound_remainders_round_per_the_rounding_mode() {
+        let mut mint_data = mint_data();
+        mint_data.decimals = 9;
+        mint_data.shared_decimals = 6;
+        
+        // 1_234_567_891 is 1_234_567.891 shared units
+        assert_eq!(mint_data.split_outbound(None, 1_234_567_891), Err(ErrorCode::ChainDecimalsNotSet.into()));
+        let config = ChainConfig {
+            chain: SourceChain::Legacy(101),
+            paused: false,
+            local_decimals: 6,
+            min_transfer_amount: 0,
+        };
+        assert_eq!(mint_data.split_outbound(Some(&config), 1_234_567_891).unwrap(), (1_234_567, 891, 0, 1_234_567));
+        mint_data.rounding_mode = RoundingMode::Ceil;
+        assert_eq!(mint_data.split_outbound(Some(&config), 1_234_567_891).unwrap(), (1_234_568, 0, 109, 1_234_568));
+        // Rounding up can lift an amount below one unit to a whole one
+        assert_eq!(mint_data.split_outbound(Some(&config), 1).unwrap(), (1, 0, 999, 1));
+        mint_data.rounding_mode = RoundingMode::Reject;
+        assert_eq!(mint_data.split_outbound(Some(&config), 1_234_567_891), Err(ErrorCode::PrecisionLoss.into()));
+        
+        // Whole amounts pass unchanged under every mode
+        for mode in [RoundingMode::Floor, RoundingMode::Ceil, RoundingMode::Reject] {
+            mint_data.rounding_mode = mode;
+            assert_eq!(mint_data.split_outbound(Some(&config), 1_234_567_000).unwrap(), (1_234_567, 0, 0, 1_234_567));
+        }
+    }
+
+    #[test]
+    fn outbound_transfers_burn_and_record_what_the_rounding_mode_sends() {
         let mut state = mint_data();
-        state.authority = authority;
-        let mint_data = program_account(address, &state);
-        let role_address = |kind: RoleKind, member: Pubkey| {
-            Pubkey::find_program_address(&[b"role", address.as_ref(), &[kind as u8], member.as_ref()], &crate::ID).0
+        state.mint = key(8);
+        state.authority = key(2);
+        state.decimals = 9;
+        state.shared_decimals = 6;
+        state.current_supply = 10_000;
+        let mint_data_address = Pubkey::find_program_address(&[b"mint_data", key(8).as_ref()], &crate::ID).0;
+        let chain_config = ChainConfig {
+            chain: SourceChain::Legacy(101),
+            paused: false,
+            local_decimals: 6,
+            min_transfer_amount: 0,
         };
-        
-        Role::require(&mint_data, authority, RoleKind::Minter, None).unwrap();
+        let mut recipient = RemoteRecipient { address: [0u8; 32], length: 20 };
+        recipient.address[..20].copy_from_slice(&[7u8; 20]);
+        // Sends `amount` out of a 5_000 balance under `mode`, signed by the owner or by a
+        // delegate approved for `delegated`; returns the balance left, the stored MintData
+        // and the (amount_burned, dust_removed, rounded_up) of the event
+        let send = |mode, amount, delegated: Option<u64>| {
+            let mut state = state.clone();
+            state.rounding_mode = mode;
+            let mut accounts = transfer_accounts(&state);
+            accounts[3] = program_account(
+                Pubkey::find_program_address(&[b"chain_config", mint_data_address.as_ref(), &SourceChain::Legacy(101).seed()], &crate::ID).0,
+                &chain_config,
+            ).to_account_info();
+            let mut sender = SplAccount::unpack(&accounts[2].data.borrow()).unwrap();
+            sender.amount = 5_000;
+            if let Some(delegated_amount) = delegated {
+                sender.delegate = COption::Some(key(9));
+                sender.delegated_amount = delegated_amount;
+                accounts[16] = signer(key(9)).to_account_info();
+            }
+            sender.pack_into_slice(&mut accounts[2].data.borrow_mut());
+            let (sender, mint_data) = (accounts[2].clone(), accounts[1].clone());
+            let before = mint_data.data.borrow().to_vec();
+            let result = run_instruction(
+                accounts,
+                crate::instruction::CrossChainTransfer {
+                    destination_chain: 101,
+                    recipient,
+                    amount,
+                    nonce: [1u8; 32],
+                    dest_payload: Vec::new(),
+                    lz_params: None,
+                },
+            ).0;
+            let balance = SplAccount::unpack(&sender.data.borrow()).unwrap().amount;
+            if let Err(err) = result {
+                // A refused transfer leaves everything as it was
+                assert_eq!((balance, mint_data.data.borrow().to_vec()), (5_000, before));
+                return Err(err);
+            }
+            let stored = MintData::try_deserialize(&mut &mint_data.data.borrow()[..]).unwrap();
+            let initiated = EVENTS.take()
+                .iter()
+                .filter(|data| data.starts_with(&<CrossChainTransferInitiated as anchor_lang::Discriminator>::DISCRIMINATOR))
+                .map(|data| CrossChainTransferInitiated::try_from_slice(&data[8..]).unwrap())
+                .map(|event| (event.amount_burned, event.dust_removed, event.rounded_up))
+                .collect::<Vec<_>>();
+            Ok((balance, stored, initiated))
+        };
+        
+        // 1_500 is 1.5 shared units: rounded down the half unit stays with the sender
+        let (balance, stored, initiated) = send(RoundingMode::Floor, 1_500, None).unwrap();
+        assert_eq!((balance, initiated), (4_000, vec![(1_000, 500, 0)]));
+        assert_eq!((stored.current_supply, stored.rounded_down, stored.rounded_up), (9_000, 500, 0));
+        
+        // Rounded up the sender pays the rest of the unit, even for a single base unit
+        let (balance, stored, initiated) = send(RoundingMode::Ceil, 1_500, None).unwrap();
+        assert_eq!((balance, initiated), (3_000, vec![(2_000, 0, 500)]));
+        assert_eq!((stored.current_supply, stored.rounded_down, stored.rounded_up), (8_000, 0, 500));
+        let (balance, stored, initiated) = send(RoundingMode::Ceil, 1, None).unwrap();
+        assert_eq!((balance, initiated, stored.rounded_up), (4_000, vec![(1_000, 0, 999)], 999));
+        
+        // A delegate must be approved for the rounded amount, not just the requested one
+        assert_eq!(send(RoundingMode::Ceil, 1_500, Some(1_500)).err(), Some(Error::from(ErrorCode::Unauthorized).into()));
+        assert_eq!(send(RoundingMode::Ceil, 1_500, Some(2_000)).unwrap().0, 3_000);
+        
+        assert_eq!(send(RoundingMode::Reject, 1_500, None).err(), Some(Error::from(ErrorCode::PrecisionLoss).into()));
+        assert_eq!(send(RoundingMode::Reject, 2_000, None).unwrap().0, 3_000);
+        
+        // Quotes show the top-up before the sender signs
+        state.rounding_mode = RoundingMode::Ceil;
+        let quote_accounts = vec![
+            program_account(mint_data_address, &state).to_account_info(),
+            program_account(
+                Pubkey::find_program_address(&[b"chain_config", mint_data_address.as_ref(), &SourceChain::Legacy(101).seed()], &crate::ID).0,
+                &chain_config,
+            ).to_account_info(),
+            program_account(
+                Pubkey::find_program_address(&[b"trusted_remote", mint_data_address.as_ref(), &101u16.to_le_bytes()], &crate::ID).0,
+                &TrustedRemote { chain_id: 101, remote_address: [1u8; 32], address_length: 20, last_attested_ts: 0 },
+            ).to_account_info(),
+            uncreated_info(
+                Pubkey::find_program_address(&[b"enforced_options", mint_data_address.as_ref(), &101u16.to_le_bytes()], &crate::ID).0,
+            ).clone(),
+        ];
+        let (result, _, return_data) = run_instruction(
+            quote_accounts,
+            crate::instruction::QuoteSend { destination_chain: 101, amount: 1_500, options: Vec::new() },
+        );
+        result.unwrap();
+        let quote = SendQuote::try_from_slice(&return_data).unwrap();
+        assert_eq!((quote.amount_sent, quote.dust_removed, quote.rounded_up), (2_000, 0, 500));
+        
+        // Cancelling a held transfer gives the top-up back and takes it off the total
+        state.settlement_delay = 60;
+        let mut accounts = transfer_accounts(&state);
+        accounts[3] = program_account(
+            Pubkey::find_program_address(&[b"chain_config", mint_data_address.as_ref(), &SourceChain::Legacy(101).seed()], &crate::ID).0,
+            &chain_config,
+        ).to_account_info();
+        let pending = uncreated_info(
+            Pubkey::find_program_address(&[b"pending_outbound", mint_data_address.as_ref(), &[1u8; 32]], &crate::ID).0,
+        );
+        accounts[14] = pending.clone();
+        let mut sender = SplAccount::unpack(&accounts[2].data.borrow()).unwrap();
+        sender.amount = 5_000;
+        sender.pack_into_slice(&mut accounts[2].data.borrow_mut());
+        let omitted = || leaked_info(crate::ID, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone();
+        let cancel_accounts = vec![
+            accounts[0].clone(),
+            accounts[1].clone(),
+            pending.clone(),
+            accounts[2].clone(),
+            omitted(),
+            accounts[4].clone(),
+            accounts[5].clone(),
+            omitted(),
+            leaked_info(MintData::mint_authority_address(&mint_data_address), System::id(), Vec::new(), false).clone(),
+            signer(key(2)).to_account_info(),
+            leaked_info(anchor_spl::token::ID, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
+        ];
+        let (sender, mint_data) = (accounts[2].clone(), accounts[1].clone());
+        let stored = || MintData::try_deserialize(&mut &mint_data.data.borrow()[..]).unwrap();
+        run_instruction(
+            accounts,
+            crate::instruction::CrossChainTransfer {
+                destination_chain: 101,
+                recipient,
+                amount: 1_500,
+                nonce: [1u8; 32],
+                dest_payload: Vec::new(),
+                lz_params: None,
+            },
+        ).0.unwrap();
+        assert_eq!(stored().rounded_up, 500);
+        run_instruction(cancel_accounts, crate::instruction::CancelOutbound { _nonce: [1u8; 32] }).0.unwrap();
+        assert_eq!(SplAccount::unpack(&sender.data.borrow()).unwrap().amount, 5_000);
+        assert_eq!((stored().current_supply, stored().rounded_down, stored().rounded_up), (10_000, 0, 0));
+    }
+
+    #[test]
+    fn roles_are_bound_to_their_kind_and_member() {
+        let (address, authority, minter, other) = (key(1), key(2), key(3), key(4));
+        let mut state = mint_data();
+        state.authority = authority;
+        let mint_data = program_account(address, &state);
+        let role_address = |kind: RoleKind, member: Pubkey| {
+            Pubkey::find_program_address(&[b"role", address.as_ref(), &[kind as u8], member.as_ref()], &crate::ID).0
+        };
+        
+        Role::require(&mint_data, authority, RoleKind::Minter, None).unwrap();
         assert_eq!(
             Role::require(&mint_data, minter, RoleKind::Minter, None),
             Err(ErrorCode::InvalidAuthority.into())
@@ -13200,7 +13814,7 @@ mod tests {
         );
         assert_eq!(
             mint_data.split_outbound(Some(&config), 6_999).unwrap(),
-            (6, 999, 6 * 10u128.pow(12))
+            (6, 999, 0, 6 * 10u128.pow(12))
         );
         
         // Only 5_000 of the sender's 5_999 would actually leave, below the chain's minimum
@@ -13405,21 +14019,21 @@ mod tests {
         let mut state = mint_data();
         
         // No registry bound: nothing to look up
-        NonceRegistry::require_unused(key(1), &state, None, &[], legacy).unwrap();
+        NonceRegistry::require_unused(key(1), &state, None, &[], legacy, 0).unwrap();
         
         state.nonce_registry = key(2);
         state.nonce_bloom_insert(&legacy);
         let registry = NonceRegistry { used_nonces: vec![legacy] };
         assert_eq!(
-            NonceRegistry::require_unused(key(1), &state, Some(&registry), &[], legacy),
+            NonceRegistry::require_unused(key(1), &state, Some(&registry), &[], legacy, 0),
             Err(ErrorCode::NonceAlreadyUsed.into())
         );
         assert!(!state.nonce_bloom_may_contain(&fresh));
-        NonceRegistry::require_unused(key(1), &state, None, &[], fresh).unwrap();
+        NonceRegistry::require_unused(key(1), &state, None, &[], fresh, 0).unwrap();
         
         // A bloom hit needs the registry and every archive
         assert_eq!(
-            NonceRegistry::require_unused(key(1), &state, None, &[], legacy),
+            NonceRegistry::require_unused(key(1), &state, None, &[], legacy, 0),
             Err(ErrorCode::InvalidNonceRegistry.into())
         );
         let archived = [6u8; 32];
@@ -13427,7 +14041,7 @@ mod tests {
         state.nonce_archive_count = 1;
         let empty = NonceRegistry { used_nonces: vec![] };
         assert_eq!(
-            NonceRegistry::require_unused(key(1), &state, Some(&empty), &[], archived),
+            NonceRegistry::require_unused(key(1), &state, Some(&empty), &[], archived, 0),
             Err(ErrorCode::MissingNonceArchive.into())
         );
         let archive = program_account(key(3), &NonceArchive {
@@ -13438,11 +14052,48 @@ mod tests {
         });
         let archives = [archive.to_account_info()];
         assert_eq!(
-            NonceRegistry::require_unused(key(1), &state, Some(&empty), &archives, archived),
+            NonceRegistry::require_unused(key(1), &state, Some(&empty), &archives, archived, 0),
             Err(ErrorCode::NonceAlreadyUsed.into())
         );
     }
 
+    #[test]
+    fn legacy_nonces_are_read_until_the_dual_read_deadline() {
+        let legacy = [4u8; 32];
+        let mut state = mint_data();
+        state.nonce_registry = key(2);
+        state.nonce_bloom_insert(&legacy);
+        let registry = NonceRegistry { used_nonces: vec![legacy] };
+        
+        // Without a deadline both stores are read for good
+        assert_eq!(
+            NonceRegistry::require_unused(key(1), &state, Some(&registry), &[], legacy, i64::MAX),
+            Err(ErrorCode::NonceAlreadyUsed.into())
+        );
+        state.dual_read_until = 2_000;
+        assert_eq!(
+            NonceRegistry::require_unused(key(1), &state, Some(&registry), &[], legacy, 1_999),
+            Err(ErrorCode::NonceAlreadyUsed.into())
+        );
+        // Afterwards only the ProcessedNonce PDAs count, so the registry isn't needed
+        assert!(!state.reads_legacy_nonces(2_000));
+        NonceRegistry::require_unused(key(1), &state, None, &[], legacy, 2_000).unwrap();
+        
+        // The deadline can't be set in the past (the test clock reads 1_000)
+        let set = |dual_read_until| {
+            let mut state = mint_data();
+            state.authority = key(2);
+            let address = Pubkey::find_program_address(&[b"mint_data", state.mint.as_ref()], &crate::ID).0;
+            run_instruction(
+                vec![program_account(address, &state).to_account_info(), signer(key(2)).to_account_info()],
+                crate::instruction::SetDualReadUntil { dual_read_until },
+            ).0
+        };
+        assert_eq!(set(1_000), Err(Error::from(ErrorCode::InvalidDualReadUntil).into()));
+        set(1_001).unwrap();
+        set(0).unwrap();
+    }
+
     thread_local! {
         static INVOKED: std::cell::RefCell<Vec<Instruction>> = const { std::cell::RefCell::new(Vec::new()) };
         static RETURN_DATA: std::cell::RefCell<Vec<u8>> = const { std::cell::RefCell::new(Vec::new()) };
@@ -13770,6 +14421,7 @@ mod tests {
             pda(&[b"fee_exemption", mint_data_address.as_ref(), key(5).as_ref()]),
             omitted(),
             omitted(),
+            omitted(),
             signer(key(5)).to_account_info(),
             signer(state.authority).to_account_info(),
             leaked_info(anchor_spl::token::ID, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
@@ -14022,6 +14674,8 @@ mod tests {
         state.settlement_delay = 60;
         state.trusted_remote_count = 3;
         state.event_seq = 42;
+        state.freeze_authority_recorded = true;
+        state.freeze_authority = Some(key(6));
         let mint_data_address = Pubkey::find_program_address(&[b"mint_data", key(8).as_ref()], &crate::ID).0;
         let accounts = vec![program_account(mint_data_address, &state).to_account_info()];
 
@@ -14051,6 +14705,10 @@ mod tests {
         expected.extend_from_slice(&0u64.to_le_bytes());
         expected.extend_from_slice(&3u16.to_le_bytes());
         expected.extend_from_slice(&42u64.to_le_bytes());
+        expected.extend_from_slice(&0u64.to_le_bytes());
+        expected.extend_from_slice(&0u64.to_le_bytes());
+        expected.extend_from_slice(&[1, 1]);
+        expected.extend_from_slice(key(6).as_ref());
         assert_eq!(return_data, expected);
 
         let view = StateView::try_from_slice(&return_data).unwrap();
@@ -14059,6 +14717,69 @@ mod tests {
         assert_eq!((view.relayer, view.relayer_signer), (key(2), key(5)));
         assert!(view.config_locked && !view.paused);
         assert_eq!((view.trusted_remote_count, view.event_seq), (3, 42));
+        assert_eq!((view.freeze_authority_recorded, view.freeze_authority), (true, Some(key(6))));
+    }
+
+    #[test]
+    fn adoption_records_the_mints_freeze_authority() {
+        let mut state = mint_data();
+        state.mint = key(8);
+        state.authority = key(2);
+        let mint_data_address = Pubkey::find_program_address(&[b"mint_data", key(8).as_ref()], &crate::ID).0;
+        let adopt = |freeze_authority: COption<Pubkey>| {
+            let mut mint = vec![0u8; SplMint::LEN];
+            SplMint { mint_authority: COption::Some(key(2)), freeze_authority, is_initialized: true, ..SplMint::default() }
+                .pack_into_slice(&mut mint);
+            // Allocated at full size, like the deployed account, for the freeze authority to fit
+            let mut data = Vec::new();
+            state.try_serialize(&mut data).unwrap();
+            data.resize(MintData::LEN, 0);
+            let mint_data_info = leaked_info(mint_data_address, crate::ID, data, false).clone();
+            let accounts = vec![
+                leaked_info(key(8), anchor_spl::token::ID, mint, false).clone(),
+                mint_data_info.clone(),
+                leaked_info(MintData::mint_authority_address(&mint_data_address), System::id(), Vec::new(), false).clone(),
+                signer(key(2)).to_account_info(),
+                leaked_info(anchor_spl::token::ID, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
+            ];
+            let (result, invoked, _) = run_instruction(accounts, crate::instruction::AdoptProgramMintAuthority {});
+            result.unwrap();
+            assert_eq!(invoked.len(), 1);
+            let stored = MintData::try_deserialize(&mut &mint_data_info.data.borrow()[..]).unwrap();
+            (stored.freeze_authority_recorded, stored.freeze_authority)
+        };
+        
+        // Until adoption nothing is known about the freeze authority
+        let (result, _, return_data) = run_instruction(
+            vec![program_account(mint_data_address, &state).to_account_info()],
+            crate::instruction::ReadState {},
+        );
+        result.unwrap();
+        let view = StateView::try_from_slice(&return_data).unwrap();
+        assert_eq!((view.freeze_authority_recorded, view.freeze_authority), (false, None));
+        
+        assert_eq!(adopt(COption::Some(key(6))), (true, Some(key(6))));
+        assert_eq!(adopt(COption::None), (true, None));
+        
+        // Anyone can bring it up to date with the mint, without adopting it again
+        let refresh = |state: &MintData, freeze_authority: COption<Pubkey>| {
+            let mut mint = vec![0u8; SplMint::LEN];
+            SplMint { freeze_authority, is_initialized: true, ..SplMint::default() }.pack_into_slice(&mut mint);
+            let mut data = Vec::new();
+            state.try_serialize(&mut data).unwrap();
+            data.resize(MintData::LEN, 0);
+            let mint_data_info = leaked_info(mint_data_address, crate::ID, data, false).clone();
+            run_instruction(
+                vec![leaked_info(key(8), anchor_spl::token::ID, mint, false).clone(), mint_data_info.clone()],
+                crate::instruction::RefreshFreezeAuthority {},
+            ).0.unwrap();
+            let stored = MintData::try_deserialize(&mut &mint_data_info.data.borrow()[..]).unwrap();
+            (stored.freeze_authority_recorded, stored.freeze_authority)
+        };
+        assert_eq!(refresh(&state, COption::Some(key(7))), (true, Some(key(7))));
+        state.freeze_authority_recorded = true;
+        state.freeze_authority = Some(key(7));
+        assert_eq!(refresh(&state, COption::None), (true, None));
     }
 
     #[test]
@@ -14756,19 +15477,19 @@ mod tests {
         
         // An empty filter answers for every nonce without the registry
         assert!(!state.nonce_bloom_may_contain(&unused));
-        NonceRegistry::require_unused(key(1), &state, None, &[], unused).unwrap();
+        NonceRegistry::require_unused(key(1), &state, None, &[], unused, 0).unwrap();
         
         // A saturated filter makes every nonce a (mostly false) positive: the registry
         // decides, so unused nonces pass and used ones are still caught
         state.nonce_bloom = [0xff; MintData::NONCE_BLOOM_BYTES];
         assert!(state.nonce_bloom_may_contain(&unused));
         assert_eq!(
-            NonceRegistry::require_unused(key(1), &state, None, &[], unused),
+            NonceRegistry::require_unused(key(1), &state, None, &[], unused, 0),
             Err(ErrorCode::InvalidNonceRegistry.into())
         );
-        NonceRegistry::require_unused(key(1), &state, Some(&registry), &[], unused).unwrap();
+        NonceRegistry::require_unused(key(1), &state, Some(&registry), &[], unused, 0).unwrap();
         assert_eq!(
-            NonceRegistry::require_unused(key(1), &state, Some(&registry), &[], used),
+            NonceRegistry::require_unused(key(1), &state, Some(&registry), &[], used, 0),
             Err(ErrorCode::NonceAlreadyUsed.into())
         );
     }
@@ -15095,6 +15816,55 @@ mod tests {
         assert_eq!(SplAccount::unpack(&usable.data.borrow()).unwrap().amount, 100);
     }
 
+    #[test]
+    fn ledger_only_receives_credit_until_settled() {
+        let mut state = mint_data();
+        state.mint = key(8);
+        state.authority = key(2);
+        state.max_supply = 1_000;
+        state.trusted_remote_count = 1;
+        state.ledger_only = true;
+        let mint_data_address = Pubkey::find_program_address(&[b"mint_data", key(8).as_ref()], &crate::ID).0;
+        let mut sender = [0u8; 32];
+        sender[..20].copy_from_slice(&[1u8; 20]);
+        let mut data = vec![0u8; SplAccount::LEN];
+        SplAccount { mint: key(8), owner: key(5), state: AccountState::Initialized, ..SplAccount::default() }.pack_into_slice(&mut data);
+        let recipient = leaked_info(key(3), anchor_spl::token::ID, data, false);
+        let balance = || SplAccount::unpack(&recipient.data.borrow()).unwrap().amount;
+        
+        let mut accounts = receive_accounts(&state, SourceChain::Legacy(101), recipient, [1u8; 32]);
+        let claimable = uncreated_info(Pubkey::find_program_address(&[b"claimable", mint_data_address.as_ref(), key(5).as_ref()], &crate::ID).0);
+        accounts[6] = claimable.clone();
+        let (mint, mint_data_info, mint_authority) = (accounts[0].clone(), accounts[1].clone(), accounts[13].clone());
+        let stored = || MintData::try_deserialize(&mut &mint_data_info.data.borrow()[..]).unwrap();
+        let (result, invoked, _) = run_instruction(
+            accounts,
+            crate::instruction::ReceiveCrossChainTransfer { source_chain: 101, sender, recipient: key(5), amount: 100, nonce: [1u8; 32] },
+        );
+        result.unwrap();
+        assert!(invoked.iter().all(|ix| ix.program_id != anchor_spl::token::ID));
+        assert_eq!((balance(), stored().current_supply, stored().ledger_outstanding), (0, 0, 100));
+        
+        let settle = |authority: Pubkey| run_instruction(
+            vec![
+                mint.clone(),
+                mint_data_info.clone(),
+                claimable.clone(),
+                recipient.clone(),
+                mint_authority.clone(),
+                signer(authority).to_account_info(),
+                leaked_info(anchor_spl::token::ID, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
+            ],
+            crate::instruction::SettleLedger { recipient: key(5) },
+        ).0;
+        assert_eq!(settle(key(6)), Err(Error::from(ErrorCode::InvalidAuthority).into()));
+        settle(key(2)).unwrap();
+        assert_eq!((balance(), stored().current_supply, stored().ledger_outstanding), (100, 100, 0));
+        assert_eq!(ClaimableBalance::try_deserialize(&mut &claimable.data.borrow()[..]).unwrap().amount, 0);
+        // Nothing is left to settle twice
+        assert_eq!(settle(key(2)), Err(Error::from(ErrorCode::InvalidAmount).into()));
+    }
+
     #[test]
     fn outbound_commitments_verify_only_the_recorded_chain() {
         let transfer = |amount| CrossChainTransferInfo {
@@ -15578,8 +16348,13 @@ mod tests {
         let legacy_info = closable_info(legacy, crate::ID, data);
         let legacy_mint_authority = MintData::mint_authority_address(&legacy);
         let mut mint = vec![0u8; SplMint::LEN];
-        SplMint { mint_authority: COption::Some(legacy_mint_authority), is_initialized: true, ..SplMint::default() }
-            .pack_into_slice(&mut mint);
+        SplMint {
+            mint_authority: COption::Some(legacy_mint_authority),
+            freeze_authority: COption::Some(key(6)),
+            is_initialized: true,
+            ..SplMint::default()
+        }
+        .pack_into_slice(&mut mint);
         let mint_data_address = Pubkey::find_program_address(&[b"mint_data", key(8).as_ref()], &crate::ID).0;
         let mint_data_info = uncreated_info(mint_data_address);
         let authority = signer(key(2)).to_account_info();
@@ -15599,6 +16374,7 @@ mod tests {
         result.unwrap();
         let migrated = MintData::try_deserialize(&mut &mint_data_info.data.borrow()[..]).unwrap();
         assert_eq!((migrated.mint, migrated.authority, migrated.max_supply), (key(8), key(2), 1_000));
+        assert_eq!((migrated.freeze_authority_recorded, migrated.freeze_authority), (true, Some(key(6))));
         let set_authority = invoked.iter().find(|ix| ix.program_id == anchor_spl::token::ID).unwrap();
         assert_eq!(
             set_authority.data,
@@ -15841,6 +16617,21 @@ mod tests {
         let ata = anchor_spl::associated_token::get_associated_token_address(&key(5), &key(8));
         assert_eq!(listed[5].pubkey, ata);
         assert_eq!(listed.last().unwrap().pubkey, endpoint);
+        
+        // A bound legacy registry is listed only while replay checks still read it
+        let listed_registry = |dual_read_until| {
+            let mut state = state.clone();
+            state.nonce_registry = key(30);
+            state.dual_read_until = dual_read_until;
+            let mint_data_info = program_account(mint_data_address, &state).to_account_info();
+            let (result, _, return_data) = run_instruction(vec![mint.clone(), mint_data_info], crate::instruction::LzReceiveTypes { params: params(sender) });
+            result.unwrap();
+            Vec::<LzAccount>::try_from_slice(&return_data).unwrap()[6].pubkey
+        };
+        assert_eq!(listed[6].pubkey, crate::ID);
+        assert_eq!(listed_registry(0), key(30));
+        assert_eq!(listed_registry(2_000), key(30));
+        assert_eq!(listed_registry(1_000), crate::ID);
         let programs = [anchor_spl::token::ID, AssociatedToken::id(), System::id(), crate::ID, endpoint];
         // The executor fills in itself as the payer
         let accounts = || std::iter::once(signer(key(9)).to_account_info())
@@ -15932,7 +16723,7 @@ mod tests {
             if let Some(refund_address) = refund_address {
                 accounts[9] = refund_address.clone();
             }
-            let (fee_vault, authority) = (accounts[8].clone(), accounts[16].clone());
+            let (fee_vault, authority) = (accounts[8].clone(), accounts[17].clone());
             accounts.extend([
                 leaked_info(endpoint, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
                 fee_vault.clone(),
@@ -16123,6 +16914,290 @@ mod tests {
         transfer(90).unwrap();
     }
     
+    #[test]
+    fn held_outbound_transfers_are_published_once_the_delay_has_passed() {
+        let mut state = mint_data();
+        state.mint = key(8);
+        state.authority = key(2);
+        state.current_supply = 1_000;
+        state.settlement_delay = 60;
+        let mint_data_address = Pubkey::find_program_address(&[b"mint_data", key(8).as_ref()], &crate::ID).0;
+        let mut recipient = RemoteRecipient { address: [0u8; 32], length: 20 };
+        recipient.address[..20].copy_from_slice(&[7u8; 20]);
+        let mut accounts = transfer_accounts(&state);
+        let pending = uncreated_info(
+            Pubkey::find_program_address(&[b"pending_outbound", mint_data_address.as_ref(), &[1u8; 32]], &crate::ID).0,
+        );
+        accounts[14] = pending.clone();
+        let settle_accounts = vec![
+            accounts[1].clone(),
+            pending.clone(),
+            accounts[6].clone(),
+            accounts[7].clone(),
+            accounts[8].clone(),
+            leaked_info(crate::ID, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
+            signer(key(2)).to_account_info(),
+            leaked_info(System::id(), anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
+        ];
+        let initiated = || EVENTS.take()
+            .iter()
+            .filter(|data| data.starts_with(&<CrossChainTransferInitiated as anchor_lang::Discriminator>::DISCRIMINATOR))
+            .map(|data| CrossChainTransferInitiated::try_from_slice(&data[8..]).unwrap().amount_burned)
+            .collect::<Vec<_>>();
+        let settle = || run_instruction(
+            settle_accounts.clone(),
+            crate::instruction::SettleOutbound { _nonce: [1u8; 32], lz_params: None },
+        ).0;
+        
+        // The burn happens right away but the transfer is only announced on settlement
+        run_instruction(
+            accounts,
+            crate::instruction::CrossChainTransfer {
+                destination_chain: 101,
+                recipient,
+                amount: 40,
+                nonce: [1u8; 32],
+                dest_payload: Vec::new(),
+                lz_params: None,
+            },
+        ).0.unwrap();
+        assert!(initiated().is_empty());
+        let mut held = PendingOutbound::try_deserialize(&mut &pending.data.borrow()[..]).unwrap();
+        assert_eq!(held.settle_after, 1_060);
+        
+        assert_eq!(settle(), Err(Error::from(ErrorCode::SettlementDelayActive).into()));
+        assert!(initiated().is_empty());
+        
+        held.settle_after = 1_000;
+        held.try_serialize(&mut &mut pending.data.borrow_mut()[..]).unwrap();
+        settle().unwrap();
+        assert_eq!(initiated(), vec![40]);
+    }
+
+    #[test]
+    fn holds_created_before_fields_were_appended_still_settle() {
+        let mut state = mint_data();
+        state.mint = key(8);
+        state.authority = key(2);
+        state.current_supply = 1_000;
+        state.settlement_delay = 60;
+        let mint_data_address = Pubkey::find_program_address(&[b"mint_data", key(8).as_ref()], &crate::ID).0;
+        let mut recipient = RemoteRecipient { address: [0u8; 32], length: 20 };
+        recipient.address[..20].copy_from_slice(&[7u8; 20]);
+        let mut accounts = transfer_accounts(&state);
+        let pending_address =
+            Pubkey::find_program_address(&[b"pending_outbound", mint_data_address.as_ref(), &[1u8; 32]], &crate::ID).0;
+        let pending = uncreated_info(pending_address);
+        accounts[14] = pending.clone();
+        let (mint_data, trusted_remote, enforced_options, fee_vault) =
+            (accounts[1].clone(), accounts[6].clone(), accounts[7].clone(), accounts[8].clone());
+        run_instruction(
+            accounts,
+            crate::instruction::CrossChainTransfer {
+                destination_chain: 101,
+                recipient,
+                amount: 40,
+                nonce: [1u8; 32],
+                dest_payload: vec![9u8; CrossChainTransferInfo::MAX_PAYLOAD_LEN],
+                lz_params: None,
+            },
+        ).0.unwrap();
+        EVENTS.take();
+        
+        // A hold with the largest payload filled all of the old space, leaving nothing for
+        // the refund recipient appended since
+        let mut held = PendingOutbound::try_deserialize(&mut &pending.data.borrow()[..]).unwrap();
+        held.settle_after = 1_000;
+        let mut data = Vec::new();
+        held.try_serialize(&mut data).unwrap();
+        data.truncate(PendingOutbound::LEGACY_SPACE);
+        let legacy = runtime_info(pending_address, crate::ID, 1_000_000_000, data);
+        
+        assert_eq!(
+            <PendingOutbound as anchor_lang::Discriminator>::DISCRIMINATOR,
+            anchor_lang::solana_program::hash::hash(b"account:PendingOutbound").to_bytes()[..8]
+        );
+        let decoded = PendingOutbound::try_deserialize(&mut &legacy.data.borrow()[..]).unwrap();
+        assert_eq!(decoded.refund_recipient, None);
+        assert_eq!(decoded.refund_account(), key(3));
+        assert_eq!(decoded.transfer_info.dest_payload, vec![9u8; CrossChainTransferInfo::MAX_PAYLOAD_LEN]);
+        
+        // One that named a refund recipient before `rounded_up` was appended keeps it
+        let mut named = held.clone();
+        named.refund_recipient = Some(key(11));
+        named.rounded_up = 7;
+        let mut data = Vec::new();
+        named.try_serialize(&mut data).unwrap();
+        data.truncate(PendingOutbound::SPACE - 8);
+        let decoded = PendingOutbound::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!((decoded.refund_account(), decoded.amounts().rounded_up), (key(11), 0));
+        
+        run_instruction(
+            vec![
+                mint_data,
+                legacy.clone(),
+                trusted_remote,
+                enforced_options,
+                fee_vault,
+                leaked_info(crate::ID, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
+                signer(key(2)).to_account_info(),
+                leaked_info(System::id(), anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
+            ],
+            crate::instruction::SettleOutbound { _nonce: [1u8; 32], lz_params: None },
+        ).0.unwrap();
+        let initiated = EVENTS.take()
+            .iter()
+            .filter(|data| data.starts_with(&<CrossChainTransferInitiated as anchor_lang::Discriminator>::DISCRIMINATOR))
+            .map(|data| CrossChainTransferInitiated::try_from_slice(&data[8..]).unwrap().amount_burned)
+            .collect::<Vec<_>>();
+        assert_eq!(initiated, vec![40]);
+        assert_eq!(legacy.lamports(), 0);
+    }
+
+    #[test]
+    fn cancelled_outbound_is_re_minted_to_its_refund_account() {
+        let mut state = mint_data();
+        state.mint = key(8);
+        state.authority = key(2);
+        state.current_supply = 1_000;
+        state.settlement_delay = 60;
+        let mint_data_address = Pubkey::find_program_address(&[b"mint_data", key(8).as_ref()], &crate::ID).0;
+        let mut recipient = RemoteRecipient { address: [0u8; 32], length: 20 };
+        recipient.address[..20].copy_from_slice(&[7u8; 20]);
+        let token_account = |address: Pubkey, mint: Pubkey| {
+            let mut data = vec![0u8; SplAccount::LEN];
+            SplAccount { mint, owner: key(6), state: AccountState::Initialized, ..SplAccount::default() }
+                .pack_into_slice(&mut data);
+            leaked_info(address, anchor_spl::token::ID, data, false)
+        };
+        let balance = |info: &AccountInfo| SplAccount::unpack(&info.data.borrow()).unwrap().amount;
+        let omitted = || leaked_info(crate::ID, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone();
+        // Holds 40 of the sender's 100 tokens back, naming `refund_recipient` if given, then
+        // cancels it with `refund_to` passed; returns the sender's balance and the event
+        let hold_and_cancel = |refund_recipient: Option<&AccountInfo<'static>>, refund_to: Option<&AccountInfo<'static>>| {
+            let mut accounts = transfer_accounts(&state);
+            let pending = uncreated_info(
+                Pubkey::find_program_address(&[b"pending_outbound", mint_data_address.as_ref(), &[1u8; 32]], &crate::ID).0,
+            );
+            accounts[14] = pending.clone();
+            if let Some(refund_recipient) = refund_recipient {
+                accounts[15] = refund_recipient.clone();
+            }
+            let (mint, mint_data, sender, bridge_volume, rate_limit) =
+                (accounts[0].clone(), accounts[1].clone(), accounts[2].clone(), accounts[4].clone(), accounts[5].clone());
+            run_instruction(
+                accounts,
+                crate::instruction::CrossChainTransfer {
+                    destination_chain: 101,
+                    recipient,
+                    amount: 40,
+                    nonce: [1u8; 32],
+                    dest_payload: Vec::new(),
+                    lz_params: None,
+                },
+            ).0?;
+            let held = PendingOutbound::try_deserialize(&mut &pending.data.borrow()[..]).unwrap();
+            assert_eq!(held.refund_recipient, refund_recipient.map(|info| *info.key));
+            
+            let (result, _, _) = run_instruction(
+                vec![
+                    mint,
+                    mint_data,
+                    pending.clone(),
+                    sender.clone(),
+                    refund_to.cloned().unwrap_or_else(omitted),
+                    bridge_volume,
+                    rate_limit,
+                    omitted(),
+                    leaked_info(MintData::mint_authority_address(&mint_data_address), System::id(), Vec::new(), false).clone(),
+                    signer(key(2)).to_account_info(),
+                    leaked_info(anchor_spl::token::ID, anchor_lang::solana_program::bpf_loader::ID, Vec::new(), true).clone(),
+                ],
+                crate::instruction::CancelOutbound { _nonce: [1u8; 32] },
+            );
+            result?;
+            let cancelled = EVENTS.take()
+                .iter()
+                .filter(|data| data.starts_with(&<OutboundCancelled as anchor_lang::Discriminator>::DISCRIMINATOR))
+                .map(|data| OutboundCancelled::try_from_slice(&data[8..]).unwrap())
+                .map(|event| (event.refunded_to, event.amount))
+                .collect::<Vec<_>>();
+            Ok::<_, ProgramError>((balance(&sender), cancelled))
+        };
+        
+        // By default the burned amount goes back to the sender account
+        assert_eq!(hold_and_cancel(None, None), Ok((100, vec![(key(3), 40)])));
+        
+        // A custodial refund recipient gets it instead, and only it can be refunded
+        let custodian = token_account(key(11), key(8));
+        assert_eq!(
+            hold_and_cancel(Some(custodian), None),
+            Err(Error::from(ErrorCode::InvalidRecipient).into())
+        );
+        assert_eq!(
+            hold_and_cancel(Some(custodian), Some(token_account(key(12), key(8)))),
+            Err(Error::from(ErrorCode::InvalidRecipient).into())
+        );
+        assert_eq!(hold_and_cancel(Some(custodian), Some(custodian)), Ok((60, vec![(key(11), 40)])));
+        assert_eq!(balance(custodian), 40);
+        
+        // It must hold the mint, and is only taken for transfers that are held
+        assert_eq!(
+            hold_and_cancel(Some(token_account(key(12), key(9))), None),
+            Err(Error::from(ErrorCode::WrongMint).into())
+        );
+        state.settlement_delay = 0;
+        let mut accounts = transfer_accounts(&state);
+        accounts[15] = custodian.clone();
+        let (result, _, _) = run_instruction(
+            accounts,
+            crate::instruction::CrossChainTransfer {
+                destination_chain: 101,
+                recipient,
+                amount: 40,
+                nonce: [1u8; 32],
+                dest_payload: Vec::new(),
+                lz_params: None,
+            },
+        );
+        assert_eq!(result, Err(Error::from(ErrorCode::PendingOutboundNotHeld).into()));
+        
+        // A delegate can hold the owner's tokens back, but a refund of them stays the owner's
+        state.settlement_delay = 60;
+        let delegated_transfer = |refund_recipient: Option<&AccountInfo<'static>>| {
+            let mut accounts = transfer_accounts(&state);
+            let mut sender = SplAccount::unpack(&accounts[2].data.borrow()).unwrap();
+            sender.delegate = COption::Some(key(9));
+            sender.delegated_amount = 100;
+            sender.pack_into_slice(&mut accounts[2].data.borrow_mut());
+            let pending = uncreated_info(
+                Pubkey::find_program_address(&[b"pending_outbound", mint_data_address.as_ref(), &[1u8; 32]], &crate::ID).0,
+            );
+            accounts[14] = pending.clone();
+            if let Some(refund_recipient) = refund_recipient {
+                accounts[15] = refund_recipient.clone();
+            }
+            accounts[16] = signer(key(9)).to_account_info();
+            let (result, _, _) = run_instruction(
+                accounts,
+                crate::instruction::CrossChainTransfer {
+                    destination_chain: 101,
+                    recipient,
+                    amount: 40,
+                    nonce: [1u8; 32],
+                    dest_payload: Vec::new(),
+                    lz_params: None,
+                },
+            );
+            result.map(|()| PendingOutbound::try_deserialize(&mut &pending.data.borrow()[..]).unwrap().refund_account())
+        };
+        assert_eq!(
+            delegated_transfer(Some(token_account(key(13), key(8)))),
+            Err(Error::from(ErrorCode::Unauthorized).into())
+        );
+        assert_eq!(delegated_transfer(None), Ok(key(3)));
+    }
+    
     #[test]
     fn large_receives_wait_in_quarantine_until_claimed_or_vetoed() {
         let mut state = mint_data();
@@ -16214,6 +17289,172 @@ mod tests {
         assert_eq!((stored().current_supply, stored().ledger_outstanding), (120, 0));
     }
 
+    #[test]
+    fn batch_claims_mint_only_the_transfers_out_of_quarantine() {
+        let mut state = mint_data();
+        state.mint = key(8);
+        state.authority = key(2);
+        state.max_supply = 1_000;
+        state.trusted_remote_count = 1;
+        state.quarantine_threshold = 50;
+        let mut sender = [0u8; 32];
+        sender[..20].copy_from_slice(&[1u8; 20]);
+        let mut data = vec![0u8; SplAccount::LEN];
+        SplAccount { mint: key(8), owner: key(5), state: AccountState::Initialized, ..SplAccount::default() }.pack_into_slice(&mut data);
+        let recipient = leaked_info(key(3), anchor_spl::token::ID, data, false);
+        let shared = receive_accounts(&state, SourceChain::Legacy(101), recipient, [0u8; 32]);
+        let stored = || MintData::try_deserialize(&mut &shared[1].data.borrow()[..]).unwrap();
+        // Quarantines `amount` for `delay` seconds; returns its nonce, PendingInbound and payer
+        let quarantine = |amount, nonce, delay| {
+            let mut state = stored();
+            state.quarantine_delay = delay;
+            state.try_serialize(&mut &mut shared[1].data.borrow_mut()[..]).unwrap();
+            let mut accounts = receive_accounts(&state, SourceChain::Legacy(101), recipient, nonce);
+            accounts[0] = shared[0].clone();
+            accounts[1] = shared[1].clone();
+            let pending = (nonce, accounts[11].clone(), accounts[16].clone());
+            run_instruction(
+                accounts,
+                crate::instruction::ReceiveCrossChainTransfer { source_chain: 101, sender, recipient: key(5), amount, nonce },
+            ).0.unwrap();
+            pending
+        };
+        let entries = [quarantine(60, [1u8; 32], 0), quarantine(70, [2u8; 32], 3_600), quarantine(80, [3u8; 32], 0)];
+        assert_eq!((stored().current_supply, stored().ledger_outstanding, stored().pending_inbound_count), (0, 210, 3));
+        EVENTS.take();
+        let claim_batch = |entries: &[([u8; 32], AccountInfo<'static>, AccountInfo<'static>)]| {
+            let mut accounts = vec![shared[0].clone(), shared[1].clone(), shared[13].clone(), shared[17].clone()];
+            for (_, pending, payer) in entries {
+                accounts.extend([pending.clone(), recipient.clone(), payer.clone()]);
+            }
+            let entries = entries.iter()
+                .map(|&(nonce, _, _)| PendingInboundId { source: SourceChain::Legacy(101), nonce })
+                .collect();
+            run_instruction(accounts, crate::instruction::ClaimPendingInboundBatch { entries }).0
+        };
+        
+        // Every entry must name the payer of its PendingInbound, and be named by its seeds
+        let (nonce, pending, _) = &entries[0];
+        assert_eq!(
+            claim_batch(&[(*nonce, pending.clone(), signer(key(6)).to_account_info())]),
+            Err(Error::from(ErrorCode::InvalidRecipient).into())
+        );
+        let (_, other, payer) = &entries[1];
+        assert_eq!(
+            claim_batch(&[(*nonce, other.clone(), payer.clone())]),
+            Err(Error::from(ErrorCode::InvalidPendingInbound).into())
+        );
+        assert_eq!(claim_batch(&[]), Err(Error::from(ErrorCode::InvalidBatchSize).into()));
+        let oversized = vec![entries[0].clone(); ClaimPendingInboundBatch::MAX_BATCH + 1];
+        assert_eq!(claim_batch(&oversized), Err(Error::from(ErrorCode::InvalidBatchSize).into()));
+        
+        // Nothing is claimed while inbound transfers are paused
+        let set_paused_ops = |paused_ops| {
+            let mut state = stored();
+            state.paused_ops = paused_ops;
+            state.try_serialize(&mut &mut shared[1].data.borrow_mut()[..]).unwrap();
+        };
+        set_paused_ops(MintData::OP_BRIDGE_IN);
+        assert_eq!(claim_batch(&entries), Err(Error::from(ErrorCode::OperationPaused).into()));
+        set_paused_ops(0);
+        assert!(EVENTS.take().is_empty());
+        
+        claim_batch(&entries).unwrap();
+        let summary = EVENTS.take()
+            .iter()
+            .filter(|data| data.starts_with(&<PendingInboundBatchClaimed as anchor_lang::Discriminator>::DISCRIMINATOR))
+            .map(|data| PendingInboundBatchClaimed::try_from_slice(&data[8..]).unwrap())
+            .map(|event| (event.claimed, event.skipped, event.new_supply))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, vec![(2, 1, 140)]);
+        assert_eq!(SplAccount::unpack(&recipient.data.borrow()).unwrap().amount, 140);
+        assert_eq!((stored().current_supply, stored().ledger_outstanding, stored().pending_inbound_count), (140, 70, 1));
+        
+        // Claimed accounts are closed; the one still in quarantine stays open
+        let open = entries.iter().map(|(_, pending, _)| *pending.owner == crate::ID).collect::<Vec<_>>();
+        assert_eq!(open, vec![false, true, false]);
+        
+        // Entries already claimed, or listed twice, are skipped rather than failing the batch
+        let repeated = quarantine(90, [4u8; 32], 0);
+        assert_eq!(claim_batch(&[entries[0].clone(), repeated.clone(), repeated]), Ok(()));
+        let summary = EVENTS.take()
+            .iter()
+            .filter(|data| data.starts_with(&<PendingInboundBatchClaimed as anchor_lang::Discriminator>::DISCRIMINATOR))
+            .map(|data| PendingInboundBatchClaimed::try_from_slice(&data[8..]).unwrap())
+            .map(|event| (event.claimed, event.skipped, event.new_supply))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, vec![(1, 2, 230)]);
+    }
+
+    #[test]
+    fn quarantine_holds_at_most_max_pending_inbound_transfers() {
+        let mut state = mint_data();
+        state.mint = key(8);
+        state.authority = key(2);
+        state.max_supply = 1_000;
+        state.trusted_remote_count = 1;
+        state.quarantine_threshold = 50;
+        state.quarantine_delay = 3_600;
+        state.max_pending_inbound = 1;
+        let mut sender = [0u8; 32];
+        sender[..20].copy_from_slice(&[1u8; 20]);
+        let mut data = vec![0u8; SplAccount::LEN];
+        SplAccount { mint: key(8), owner: key(5), state: AccountState::Initialized, ..SplAccount::default() }.pack_into_slice(&mut data);
+        let recipient = leaked_info(key(3), anchor_spl::token::ID, data, false);
+        let shared = receive_accounts(&state, SourceChain::Legacy(101), recipient, [0u8; 32]);
+        let stored = || MintData::try_deserialize(&mut &shared[1].data.borrow()[..]).unwrap();
+        let receive = |amount, nonce| {
+            let mut accounts = receive_accounts(&state, SourceChain::Legacy(101), recipient, nonce);
+            accounts[1] = shared[1].clone();
+            let pending = (accounts[11].clone(), accounts[16].clone());
+            run_instruction(
+                accounts,
+                crate::instruction::ReceiveCrossChainTransfer { source_chain: 101, sender, recipient: key(5), amount, nonce },
+            ).0.map(|()| pending)
+        };
+        
+        let (pending, payer) = receive(60, [1u8; 32]).unwrap();
+        assert_eq!(stored().pending_inbound_count, 1);
+        assert_eq!(receive(70, [2u8; 32]).err(), Some(Error::from(ErrorCode::TooManyPending).into()));
+        // Transfers that mint right away don't need a slot
+        receive(40, [3u8; 32]).unwrap();
+        
+        // Vetoing the held transfer frees its slot
+        run_instruction(
+            vec![shared[1].clone(), pending, payer, signer(key(2)).to_account_info()],
+            crate::instruction::VetoPendingInbound {},
+        ).0.unwrap();
+        assert_eq!(stored().pending_inbound_count, 0);
+        let (pending, payer) = receive(70, [2u8; 32]).unwrap();
+        assert_eq!((stored().pending_inbound_count, stored().ledger_outstanding), (1, 70));
+        
+        // So does claiming it once unlocked, letting the next quarantined transfer in
+        let mut held = PendingInbound::try_deserialize(&mut &pending.data.borrow()[..]).unwrap();
+        held.unlock_ts = 1_000;
+        held.try_serialize(&mut &mut pending.data.borrow_mut()[..]).unwrap();
+        run_instruction(
+            vec![
+                shared[0].clone(),
+                shared[1].clone(),
+                pending,
+                recipient.clone(),
+                payer,
+                shared[13].clone(),
+                shared[17].clone(),
+            ],
+            crate::instruction::ClaimPendingInbound {},
+        ).0.unwrap();
+        assert_eq!((stored().pending_inbound_count, stored().ledger_outstanding), (0, 0));
+        receive(80, [4u8; 32]).unwrap();
+        assert_eq!((stored().pending_inbound_count, stored().ledger_outstanding), (1, 80));
+        
+        // Holds that predate the count never take it below zero
+        let mut state = stored();
+        state.pending_inbound_count = 0;
+        state.close_pending_inbound();
+        assert_eq!(state.pending_inbound_count, 0);
+    }
+
     #[test]
     fn rotated_nonces_stay_rejected_through_their_archive() {
         host_syscalls();
@@ -16250,10 +17491,10 @@ mod tests {
         let archive = program_account(key(7), &*rotated.nonce_archive);
         let archives = [archive.to_account_info()];
         assert_eq!(
-            NonceRegistry::require_unused(mint_data_address, &rotated.mint_data, Some(&rotated.nonce_registry), &archives, archived),
+            NonceRegistry::require_unused(mint_data_address, &rotated.mint_data, Some(&rotated.nonce_registry), &archives, archived, 0),
             Err(ErrorCode::NonceAlreadyUsed.into())
         );
-        NonceRegistry::require_unused(mint_data_address, &rotated.mint_data, Some(&rotated.nonce_registry), &archives, fresh).unwrap();
+        NonceRegistry::require_unused(mint_data_address, &rotated.mint_data, Some(&rotated.nonce_registry), &archives, fresh, 0).unwrap();
     }
 
     #[test]